@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use csv::{ReaderBuilder, Trim};
 use regex::Regex;
 use lazy_static::lazy_static;
+use js_sys::Math;
 
 #[wasm_bindgen]
 extern "C" {
@@ -16,9 +17,8 @@ macro_rules! console_log {
 }
 
 lazy_static! {
-    static ref DATE_PATTERNS: [Regex; 3] = [
+    static ref DATE_PATTERNS: [Regex; 2] = [
         Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap(),
-        Regex::new(r"^\d{2}/\d{2}/\d{4}$").unwrap(),
         Regex::new(r"^\d{2}/\d{2}/\d{4}$").unwrap()
     ];
     static ref DATETIME_PATTERN: Regex = 
@@ -41,6 +41,10 @@ struct Column {
     type_name: String,
     type_details: TypeDetails,
     unique_values: usize,
+    /// True when `unique_values` is a lower-bound estimate rather than an
+    /// exact count (set by the chunked API once a column's distinct-value
+    /// tracking hits its cap); mirrors `frequency.cardinality_is_estimate`.
+    unique_values_is_estimate: bool,
     null_count: usize,
     min_value: Option<String>,
     max_value: Option<String>,
@@ -50,6 +54,178 @@ struct Column {
     valid_count: usize,
     total_count: usize,
     analyzed_count: usize,
+    numeric_stats: Option<NumericStats>,
+    frequency: FrequencyAnalysis,
+}
+
+#[derive(Serialize, Deserialize)]
+struct FrequencyEntry {
+    value: String,
+    count: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct FrequencyAnalysis {
+    cardinality: usize,
+    cardinality_is_estimate: bool,
+    modes: Vec<String>,
+    mode_count: usize,
+    antimodes: Vec<String>,
+    antimode_count: usize,
+    top_values: Vec<FrequencyEntry>,
+    frequency_table: Option<Vec<FrequencyEntry>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct NumericStats {
+    count: usize,
+    sum: f64,
+    mean: Option<f64>,
+    variance: Option<f64>,
+    sample_variance: Option<f64>,
+    stddev: Option<f64>,
+    sparsity: Option<f64>,
+    median: Option<f64>,
+    q1: Option<f64>,
+    q3: Option<f64>,
+    iqr: Option<f64>,
+    lower_fence: Option<f64>,
+    upper_fence: Option<f64>,
+    skewness: Option<f64>,
+}
+
+/// Streaming quantile estimator (Jain & Chlamtac's P² algorithm), tracking
+/// five markers for min, Q1, Q2 (median), Q3 and max in O(1) memory.
+struct P2QuantileEstimator {
+    initial: Vec<f64>,
+    heights: [f64; 5],
+    positions: [i64; 5],
+    desired_positions: [f64; 5],
+    increments: [f64; 5],
+    initialized: bool,
+}
+
+impl P2QuantileEstimator {
+    fn new() -> Self {
+        P2QuantileEstimator {
+            initial: Vec::with_capacity(5),
+            heights: [0.0; 5],
+            positions: [1, 2, 3, 4, 5],
+            desired_positions: [1.0, 2.0, 3.0, 4.0, 5.0],
+            increments: [0.0, 0.25, 0.5, 0.75, 1.0],
+            initialized: false,
+        }
+    }
+
+    fn observe(&mut self, x: f64) {
+        if !self.initialized {
+            self.initial.push(x);
+            if self.initial.len() == 5 {
+                self.initial.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+                for i in 0..5 {
+                    self.heights[i] = self.initial[i];
+                }
+                self.initialized = true;
+            }
+            return;
+        }
+
+        let mut k = if x < self.heights[0] {
+            self.heights[0] = x;
+            0
+        } else if x >= self.heights[4] {
+            self.heights[4] = x;
+            3
+        } else {
+            let mut k = 0;
+            for i in 0..4 {
+                if self.heights[i] <= x && x < self.heights[i + 1] {
+                    k = i;
+                    break;
+                }
+            }
+            k
+        };
+        if k > 3 {
+            k = 3;
+        }
+
+        for i in (k + 1)..5 {
+            self.positions[i] += 1;
+        }
+        for i in 0..5 {
+            self.desired_positions[i] += self.increments[i];
+        }
+
+        for i in 1..4 {
+            let d = self.desired_positions[i] - self.positions[i] as f64;
+            if (d >= 1.0 && self.positions[i + 1] - self.positions[i] > 1)
+                || (d <= -1.0 && self.positions[i - 1] - self.positions[i] < -1)
+            {
+                let sign = if d >= 0.0 { 1i64 } else { -1i64 };
+                let qp = self.parabolic(i, sign);
+                let new_height = if self.heights[i - 1] < qp && qp < self.heights[i + 1] {
+                    qp
+                } else {
+                    self.linear(i, sign)
+                };
+                self.heights[i] = new_height;
+                self.positions[i] += sign;
+            }
+        }
+    }
+
+    fn parabolic(&self, i: usize, d: i64) -> f64 {
+        let n = &self.positions;
+        let q = &self.heights;
+        let d = d as f64;
+        q[i] + d / (n[i + 1] - n[i - 1]) as f64
+            * (((n[i] - n[i - 1]) as f64 + d) * (q[i + 1] - q[i]) / (n[i + 1] - n[i]) as f64
+                + ((n[i + 1] - n[i]) as f64 - d) * (q[i] - q[i - 1]) / (n[i] - n[i - 1]) as f64)
+    }
+
+    fn linear(&self, i: usize, d: i64) -> f64 {
+        let n = &self.positions;
+        let q = &self.heights;
+        q[i] + d as f64 * (q[(i as i64 + d) as usize] - q[i]) / (n[(i as i64 + d) as usize] - n[i]) as f64
+    }
+
+    /// Returns (min, q1, median, q3, max), falling back to exact computation
+    /// on the partially-filled initial window if fewer than 5 values were seen.
+    fn finish(mut self) -> (Option<f64>, Option<f64>, Option<f64>, Option<f64>, Option<f64>) {
+        if !self.initialized {
+            if self.initial.is_empty() {
+                return (None, None, None, None, None);
+            }
+            self.initial.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            let n = self.initial.len();
+            let percentile = |p: f64| -> f64 {
+                let idx = p * (n as f64 - 1.0);
+                let lo = idx.floor() as usize;
+                let hi = idx.ceil() as usize;
+                if lo == hi {
+                    self.initial[lo]
+                } else {
+                    self.initial[lo] + (idx - lo as f64) * (self.initial[hi] - self.initial[lo])
+                }
+            };
+            return (
+                Some(self.initial[0]),
+                Some(percentile(0.25)),
+                Some(percentile(0.5)),
+                Some(percentile(0.75)),
+                Some(self.initial[n - 1]),
+            );
+        }
+
+        (
+            Some(self.heights[0]),
+            Some(self.heights[1]),
+            Some(self.heights[2]),
+            Some(self.heights[3]),
+            Some(self.heights[4]),
+        )
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -57,6 +233,8 @@ struct TypeDetails {
     subtypes: Vec<String>,
     confidence: f64,
     format_examples: Vec<String>,
+    detected_format: Option<String>,
+    ambiguous: bool,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -69,9 +247,22 @@ struct Analysis {
 }
 
 #[wasm_bindgen]
-#[derive(Default)]
 pub struct AnalyzerConfig {
     sample_size: Option<usize>,
+    streaming: bool,
+    top_n: usize,
+    include_frequency_table: bool,
+}
+
+impl Default for AnalyzerConfig {
+    fn default() -> Self {
+        AnalyzerConfig {
+            sample_size: None,
+            streaming: false,
+            top_n: 10,
+            include_frequency_table: false,
+        }
+    }
 }
 
 #[wasm_bindgen]
@@ -85,11 +276,432 @@ impl AnalyzerConfig {
     pub fn set_sample_size(&mut self, size: Option<usize>) {
         self.sample_size = size;
     }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_streaming(&mut self, streaming: bool) {
+        self.streaming = streaming;
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_top_n(&mut self, top_n: usize) {
+        self.top_n = top_n;
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_include_frequency_table(&mut self, include: bool) {
+        self.include_frequency_table = include;
+    }
 }
 
 #[wasm_bindgen]
 pub struct CSVAnalyzer {
     config: AnalyzerConfig,
+    stream: Option<StreamingState>,
+}
+
+/// Per-column running state for the chunked `begin`/`feed`/`finish` API.
+/// Keeps only O(1)-sized accumulators per column instead of every raw cell,
+/// so peak memory is O(columns) rather than O(file size).
+struct ColumnAccumulator {
+    type_counts: HashMap<&'static str, usize>,
+    total_valid: usize,
+    null_count: usize,
+    total_count: usize,
+    sample_values: Vec<String>,
+    min_length: usize,
+    max_length: usize,
+    lexical_min: Option<String>,
+    lexical_max: Option<String>,
+    temporal_min: Option<(i64, String)>,
+    temporal_max: Option<(i64, String)>,
+    numeric_count: usize,
+    numeric_sum: f64,
+    numeric_mean: f64,
+    numeric_m2: f64,
+    numeric_zero_count: usize,
+    quantiles: P2QuantileEstimator,
+    frequency: HashMap<String, usize>,
+    frequency_capped: bool,
+    /// The first temporal subtype ("date", "datetime" or "time") observed in
+    /// this column. `temporal_samples`/`temporal_format` are built only from
+    /// values of this subtype, so a column that mixes subtypes doesn't infer
+    /// a format from one subtype (e.g. a single datetime value) and report it
+    /// against another (e.g. a majority of plain dates).
+    temporal_kind: Option<&'static str>,
+    /// Values classified as `temporal_kind`, kept apart from `sample_values`
+    /// (which retains the first few values of ANY type) so format inference
+    /// always sees date-shaped input instead of freezing at `None` because
+    /// the column's first few rows happened to be something else.
+    temporal_samples: Vec<String>,
+    /// Cached result of `infer_temporal_format` over `temporal_samples`,
+    /// recomputed only when a new sample is retained. Avoids re-running
+    /// candidate regexes on every row once MAX_TEMPORAL_SAMPLES are held,
+    /// since the inputs stop changing after that.
+    temporal_format: Option<(Option<String>, bool)>,
+    /// `temporal_samples.len()` as of the last `temporal_format` computation,
+    /// so it gets refreshed exactly when a new sample was retained (including
+    /// the cap-reaching one) rather than freezing one sample early.
+    temporal_format_sample_count: usize,
+}
+
+const MAX_RETAINED_SAMPLES: usize = 5;
+const MAX_TEMPORAL_SAMPLES: usize = 5;
+const MAX_TRACKED_CARDINALITY: usize = 5_000;
+
+impl ColumnAccumulator {
+    fn new() -> Self {
+        ColumnAccumulator {
+            type_counts: HashMap::new(),
+            total_valid: 0,
+            null_count: 0,
+            total_count: 0,
+            sample_values: Vec::new(),
+            min_length: 0,
+            max_length: 0,
+            lexical_min: None,
+            lexical_max: None,
+            temporal_min: None,
+            temporal_max: None,
+            numeric_count: 0,
+            numeric_sum: 0.0,
+            numeric_mean: 0.0,
+            numeric_m2: 0.0,
+            numeric_zero_count: 0,
+            quantiles: P2QuantileEstimator::new(),
+            frequency: HashMap::new(),
+            frequency_capped: false,
+            temporal_kind: None,
+            temporal_samples: Vec::new(),
+            temporal_format: None,
+            temporal_format_sample_count: 0,
+        }
+    }
+
+    fn observe(&mut self, raw_value: &str) {
+        self.total_count += 1;
+
+        let value = raw_value.trim();
+        if value.is_empty() {
+            self.null_count += 1;
+            return;
+        }
+        self.total_valid += 1;
+
+        let detected_type = CSVAnalyzer::classify_value(value);
+        *self.type_counts.entry(detected_type).or_insert(0) += 1;
+
+        let len = value.chars().count();
+        self.min_length = if self.total_valid == 1 { len } else { self.min_length.min(len) };
+        self.max_length = self.max_length.max(len);
+
+        if self.sample_values.len() < MAX_RETAINED_SAMPLES {
+            self.sample_values.push(value.to_string());
+        }
+
+        match &self.lexical_min {
+            Some(min) if min.as_str() <= value => {}
+            _ => self.lexical_min = Some(value.to_string()),
+        }
+        match &self.lexical_max {
+            Some(max) if max.as_str() >= value => {}
+            _ => self.lexical_max = Some(value.to_string()),
+        }
+
+        let normalized = value.replace(',', ".");
+        if let Ok(x) = normalized.parse::<f64>() {
+            self.numeric_count += 1;
+            self.numeric_sum += x;
+            if x == 0.0 {
+                self.numeric_zero_count += 1;
+            }
+            let delta = x - self.numeric_mean;
+            self.numeric_mean += delta / self.numeric_count as f64;
+            self.numeric_m2 += delta * (x - self.numeric_mean);
+            self.quantiles.observe(x);
+        }
+
+        if matches!(detected_type, "date" | "datetime" | "time") {
+            // A column can mix temporal subtypes (a handful of stray
+            // datetimes in an otherwise all-date column); only the first
+            // subtype seen feeds `temporal_samples`/`temporal_format`/
+            // `temporal_min`/`temporal_max`, so those never end up inferring
+            // a format from one subtype and applying it to another.
+            let kind = *self.temporal_kind.get_or_insert(detected_type);
+
+            if kind == detected_type {
+                if self.temporal_samples.len() < MAX_TEMPORAL_SAMPLES {
+                    self.temporal_samples.push(value.to_string());
+                }
+
+                // The inference only depends on `temporal_samples`, which stops
+                // growing past MAX_TEMPORAL_SAMPLES, so recompute it only until
+                // then instead of on every row.
+                if self.temporal_format_sample_count < self.temporal_samples.len() {
+                    let sample_refs: Vec<&str> = self.temporal_samples.iter().map(|s| s.as_str()).collect();
+                    let new_format = CSVAnalyzer::infer_temporal_format(&sample_refs, kind);
+                    let format_changed = self.temporal_format.as_ref().map_or(true, |old| old.0 != new_format.0);
+                    self.temporal_format = Some(new_format);
+                    self.temporal_format_sample_count = self.temporal_samples.len();
+
+                    // A later, better-informed guess can overturn an earlier one
+                    // (e.g. DD/MM/YYYY vs MM/DD/YYYY once a disambiguating sample
+                    // shows up). `temporal_min`/`temporal_max` hold epochs derived
+                    // under whatever format was current when they were recorded,
+                    // so comparing them against epochs from the new format would
+                    // silently mix units. Re-derive them from their own stored
+                    // strings under the corrected format before continuing.
+                    if format_changed {
+                        if let Some(format) = self.temporal_format.as_ref().and_then(|f| f.0.clone()) {
+                            if let Some((_, s)) = self.temporal_min.clone() {
+                                if let Some(epoch) = CSVAnalyzer::parse_temporal(&s, kind, &format) {
+                                    self.temporal_min = Some((epoch, s));
+                                }
+                            }
+                            if let Some((_, s)) = self.temporal_max.clone() {
+                                if let Some(epoch) = CSVAnalyzer::parse_temporal(&s, kind, &format) {
+                                    self.temporal_max = Some((epoch, s));
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if let Some((Some(format), _ambiguous)) = &self.temporal_format {
+                    if let Some(epoch) = CSVAnalyzer::parse_temporal(value, kind, format) {
+                        if self.temporal_min.as_ref().map_or(true, |(m, _)| epoch < *m) {
+                            self.temporal_min = Some((epoch, value.to_string()));
+                        }
+                        if self.temporal_max.as_ref().map_or(true, |(m, _)| epoch > *m) {
+                            self.temporal_max = Some((epoch, value.to_string()));
+                        }
+                    }
+                }
+            }
+        }
+
+        if self.frequency.contains_key(value) || self.frequency.len() < MAX_TRACKED_CARDINALITY {
+            *self.frequency.entry(value.to_string()).or_insert(0) += 1;
+        } else {
+            self.frequency_capped = true;
+        }
+    }
+
+    fn finish(self, name: String, config: &AnalyzerConfig) -> Column {
+        let type_name = if self.total_valid == 0 {
+            "null".to_string()
+        } else {
+            self.type_counts.iter()
+                .max_by_key(|(_, &count)| count)
+                .map(|(&t, _)| t.to_string())
+                .unwrap_or_else(|| "string".to_string())
+        };
+
+        let type_details = if self.total_valid == 0 {
+            TypeDetails {
+                subtypes: vec!["null".to_string()],
+                confidence: 1.0,
+                format_examples: vec![],
+                detected_format: None,
+                ambiguous: false,
+            }
+        } else {
+            let primary_count = *self.type_counts.get(type_name.as_str()).unwrap_or(&0);
+            let confidence = primary_count as f64 / self.total_valid as f64;
+            let threshold = self.total_valid as f64 * 0.05;
+            let subtypes: Vec<String> = self.type_counts.iter()
+                .filter(|(_, &count)| count as f64 >= threshold)
+                .map(|(&t, _)| t.to_string())
+                .collect();
+            let (detected_format, ambiguous) = if self.temporal_kind == Some(type_name.as_str()) {
+                // Reuse the format already derived from type-filtered
+                // `temporal_samples` in `observe()`, rather than recomputing
+                // from `sample_values` (which may hold non-temporal values
+                // for a column whose first few rows weren't date-like). Only
+                // valid when the cached format was derived from the same
+                // subtype as the column's reported majority type.
+                self.temporal_format.clone().unwrap_or((None, false))
+            } else {
+                let sample_refs: Vec<&str> = self.sample_values.iter().map(|s| s.as_str()).collect();
+                CSVAnalyzer::infer_temporal_format(&sample_refs, &type_name)
+            };
+
+            TypeDetails {
+                subtypes,
+                confidence,
+                format_examples: self.sample_values.iter().take(3).cloned().collect(),
+                detected_format,
+                ambiguous,
+            }
+        };
+
+        let (quantile_min, q1, median, q3, quantile_max) = self.quantiles.finish();
+
+        let numeric_stats = if type_name == "integer" || type_name == "float" {
+            if self.numeric_count == 0 {
+                Some(NumericStats {
+                    count: 0,
+                    sum: self.numeric_sum,
+                    mean: None,
+                    variance: None,
+                    sample_variance: None,
+                    stddev: None,
+                    sparsity: None,
+                    median: None,
+                    q1: None,
+                    q3: None,
+                    iqr: None,
+                    lower_fence: None,
+                    upper_fence: None,
+                    skewness: None,
+                })
+            } else {
+                let sparsity = Some(self.numeric_zero_count as f64 / self.numeric_count as f64);
+                let (variance, sample_variance, stddev) = if self.numeric_count < 2 {
+                    (None, None, None)
+                } else {
+                    let variance = self.numeric_m2 / self.numeric_count as f64;
+                    let sample_variance = self.numeric_m2 / (self.numeric_count as f64 - 1.0);
+                    (Some(variance), Some(sample_variance), Some(variance.sqrt()))
+                };
+                let iqr = match (q1, q3) {
+                    (Some(q1), Some(q3)) => Some(q3 - q1),
+                    _ => None,
+                };
+                let lower_fence = match (q1, iqr) {
+                    (Some(q1), Some(iqr)) => Some(q1 - 1.5 * iqr),
+                    _ => None,
+                };
+                let upper_fence = match (q3, iqr) {
+                    (Some(q3), Some(iqr)) => Some(q3 + 1.5 * iqr),
+                    _ => None,
+                };
+                let skewness = match (q1, median, q3, iqr) {
+                    (Some(q1), Some(median), Some(q3), Some(iqr)) if iqr != 0.0 => {
+                        Some((q3 + q1 - 2.0 * median) / iqr)
+                    }
+                    _ => None,
+                };
+
+                Some(NumericStats {
+                    count: self.numeric_count,
+                    sum: self.numeric_sum,
+                    mean: Some(self.numeric_mean),
+                    variance,
+                    sample_variance,
+                    stddev,
+                    sparsity,
+                    median,
+                    q1,
+                    q3,
+                    iqr,
+                    lower_fence,
+                    upper_fence,
+                    skewness,
+                })
+            }
+        } else {
+            None
+        };
+
+        let (min_value, max_value) = if type_name == "integer" || type_name == "float" {
+            (quantile_min.map(|v| v.to_string()), quantile_max.map(|v| v.to_string()))
+        } else if self.temporal_kind == Some(type_name.as_str()) && self.temporal_min.is_some() {
+            (self.temporal_min.map(|(_, s)| s), self.temporal_max.map(|(_, s)| s))
+        } else {
+            (self.lexical_min, self.lexical_max)
+        };
+
+        let cardinality = self.frequency.len();
+        let mut by_count: Vec<(String, usize)> = self.frequency.into_iter().collect();
+        by_count.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        let top_values: Vec<FrequencyEntry> = by_count.iter()
+            .take(config.top_n)
+            .map(|(value, count)| FrequencyEntry { value: value.clone(), count: *count })
+            .collect();
+
+        let all_unique = !self.frequency_capped && cardinality > 0
+            && by_count.iter().all(|(_, count)| *count == 1);
+
+        let (modes, mode_count) = match by_count.first() {
+            Some((_, top_count)) => {
+                let top_count = *top_count;
+                let modes: Vec<String> = by_count.iter()
+                    .take_while(|(_, count)| *count == top_count)
+                    .map(|(value, _)| value.clone())
+                    .collect();
+                (modes, top_count)
+            }
+            None => (Vec::new(), 0),
+        };
+
+        let (antimodes, antimode_count) = if all_unique {
+            (vec!["*ALL".to_string()], 1)
+        } else {
+            match by_count.last() {
+                Some((_, bottom_count)) => {
+                    let bottom_count = *bottom_count;
+                    let antimodes: Vec<String> = by_count.iter()
+                        .rev()
+                        .take_while(|(_, count)| *count == bottom_count)
+                        .map(|(value, _)| value.clone())
+                        .collect();
+                    (antimodes, bottom_count)
+                }
+                None => (Vec::new(), 0),
+            }
+        };
+
+        let frequency_table = if config.include_frequency_table {
+            Some(by_count.into_iter()
+                .map(|(value, count)| FrequencyEntry { value, count })
+                .collect())
+        } else {
+            None
+        };
+
+        let frequency = FrequencyAnalysis {
+            cardinality,
+            cardinality_is_estimate: self.frequency_capped,
+            modes,
+            mode_count,
+            antimodes,
+            antimode_count,
+            top_values,
+            frequency_table,
+        };
+
+        let valid_count = self.total_count - self.null_count;
+
+        Column {
+            name,
+            type_name,
+            type_details,
+            unique_values: cardinality,
+            unique_values_is_estimate: self.frequency_capped,
+            null_count: self.null_count,
+            min_value,
+            max_value,
+            min_length: if self.total_valid == 0 { 0 } else { self.min_length },
+            max_length: self.max_length,
+            sample_values: self.sample_values,
+            valid_count,
+            total_count: self.total_count,
+            analyzed_count: self.total_count,
+            numeric_stats,
+            frequency,
+        }
+    }
+}
+
+/// Holds the in-progress state of a chunked `begin`/`feed`/`finish` analysis.
+/// `pending` carries a record that was split across two `feed()` chunks.
+struct StreamingState {
+    headers: Vec<String>,
+    delimiter: char,
+    columns: Vec<ColumnAccumulator>,
+    row_count: usize,
+    pending: String,
 }
 
 #[wasm_bindgen]
@@ -99,6 +711,7 @@ impl CSVAnalyzer {
         console_log!("CSVAnalyzer::new()");
         CSVAnalyzer {
             config: config.unwrap_or_default(),
+            stream: None,
         }
     }
 
@@ -134,6 +747,30 @@ impl CSVAnalyzer {
         matches!(lower_value.as_str(), "true" | "false" | "1" | "0" | "yes" | "no" | "oui" | "non")
     }
 
+    fn classify_value(value: &str) -> &'static str {
+        if Self::is_boolean(value) {
+            "boolean"
+        } else if Self::is_integer(value) {
+            "integer"
+        } else if Self::is_float(value) {
+            "float"
+        } else if DATE_PATTERNS.iter().any(|pattern| pattern.is_match(value)) {
+            "date"
+        } else if DATETIME_PATTERN.is_match(value) {
+            "datetime"
+        } else if TIME_PATTERN.is_match(value) {
+            "time"
+        } else if EMAIL_PATTERN.is_match(value) {
+            "email"
+        } else if URL_PATTERN.is_match(value) {
+            "url"
+        } else if IPV4_PATTERN.is_match(value) || IPV6_PATTERN.is_match(value) {
+            "ip"
+        } else {
+            "string"
+        }
+    }
+
     fn detect_column_type(&self, values: &[&str]) -> (String, TypeDetails, usize) {
         let mut counts = HashMap::new();
         let mut format_examples = Vec::new();
@@ -149,28 +786,8 @@ impl CSVAnalyzer {
             }
             
             total_valid += 1;
-            
-            let detected_type = if Self::is_boolean(value) {
-                "boolean"
-            } else if Self::is_integer(value) {
-                "integer"
-            } else if Self::is_float(value) {
-                "float"
-            } else if DATE_PATTERNS.iter().any(|pattern| pattern.is_match(value)) {
-                "date"
-            } else if DATETIME_PATTERN.is_match(value) {
-                "datetime"
-            } else if TIME_PATTERN.is_match(value) {
-                "time"
-            } else if EMAIL_PATTERN.is_match(value) {
-                "email"
-            } else if URL_PATTERN.is_match(value) {
-                "url"
-            } else if IPV4_PATTERN.is_match(value) || IPV6_PATTERN.is_match(value) {
-                "ip"
-            } else {
-                "string"
-            };
+
+            let detected_type = Self::classify_value(value);
 
             *counts.entry(detected_type).or_insert(0) += 1;
 
@@ -184,6 +801,8 @@ impl CSVAnalyzer {
                 subtypes: vec!["null".to_string()],
                 confidence: 1.0,
                 format_examples: vec![],
+                detected_format: None,
+                ambiguous: false,
             }, sample_size);
         }
 
@@ -193,17 +812,26 @@ impl CSVAnalyzer {
             .unwrap_or(("string", 0));
 
         let confidence = primary_count as f64 / total_valid as f64;
-        
+
         let threshold = (total_valid as f64) * 0.05;
         let subtypes: Vec<String> = counts.iter()
             .filter(|(_, &count)| count as f64 >= threshold)
             .map(|(&t, _)| t.to_string())
             .collect();
 
+        let valid_values: Vec<&str> = values.iter()
+            .take(sample_size)
+            .map(|&s| s.trim())
+            .filter(|s| !s.is_empty())
+            .collect();
+        let (detected_format, ambiguous) = Self::infer_temporal_format(&valid_values, primary_type);
+
         (primary_type.to_string(), TypeDetails {
             subtypes,
             confidence,
             format_examples,
+            detected_format,
+            ambiguous,
         }, sample_size)
     }
 
@@ -231,12 +859,49 @@ impl CSVAnalyzer {
                     .map(|v| v.to_string());
                 (min, max)
             },
-            "date" | "datetime" | "time" | "string" => {
+            "date" | "datetime" | "time" => {
                 let valid_values: Vec<&str> = sampled_values.iter()
                     .map(|s| s.trim())
                     .filter(|s| !s.is_empty())
                     .collect();
-                
+
+                let (format, _ambiguous) = Self::infer_temporal_format(&valid_values, type_name);
+                match format {
+                    Some(format) => {
+                        let mut min: Option<(i64, String)> = None;
+                        let mut max: Option<(i64, String)> = None;
+
+                        for value in &valid_values {
+                            let epoch = match Self::parse_temporal(value, type_name, &format) {
+                                Some(epoch) => epoch,
+                                None => continue,
+                            };
+
+                            if min.as_ref().map_or(true, |(m, _)| epoch < *m) {
+                                min = Some((epoch, value.to_string()));
+                            }
+                            if max.as_ref().map_or(true, |(m, _)| epoch > *m) {
+                                max = Some((epoch, value.to_string()));
+                            }
+                        }
+
+                        (min.map(|(_, s)| s), max.map(|(_, s)| s))
+                    }
+                    // Could not confidently infer a format; fall back to lexical
+                    // comparison rather than guessing wrong.
+                    None => {
+                        let min = valid_values.iter().min().map(|&s| s.to_string());
+                        let max = valid_values.iter().max().map(|&s| s.to_string());
+                        (min, max)
+                    }
+                }
+            },
+            "string" => {
+                let valid_values: Vec<&str> = sampled_values.iter()
+                    .map(|s| s.trim())
+                    .filter(|s| !s.is_empty())
+                    .collect();
+
                 let min = valid_values.iter().min().map(|&s| s.to_string());
                 let max = valid_values.iter().max().map(|&s| s.to_string());
                 (min, max)
@@ -247,6 +912,371 @@ impl CSVAnalyzer {
         (result.0, result.1, sample_size)
     }
 
+    /// Infers the concrete temporal format a date/datetime/time column uses by
+    /// testing candidate patterns against the sampled values and picking the
+    /// one that parses the most rows unambiguously. Returns `(None, false)`
+    /// for non-temporal types. For slash-separated dates, `ambiguous` is true
+    /// when no sampled value has a first or second component > 12, meaning
+    /// MM/DD vs DD/MM cannot be told apart and the MM/DD guess may be wrong.
+    fn infer_temporal_format(values: &[&str], type_name: &str) -> (Option<String>, bool) {
+        match type_name {
+            "date" => {
+                let iso_matches = values.iter().filter(|v| DATE_PATTERNS[0].is_match(v)).count();
+                let slash_matches = values.iter().filter(|v| DATE_PATTERNS[1].is_match(v)).count();
+
+                if iso_matches == 0 && slash_matches == 0 {
+                    return (None, false);
+                }
+
+                if iso_matches >= slash_matches {
+                    (Some("YYYY-MM-DD".to_string()), false)
+                } else {
+                    let slash_parts: Vec<(u32, u32)> = values.iter()
+                        .filter(|v| DATE_PATTERNS[1].is_match(v))
+                        .filter_map(|v| {
+                            let mut parts = v.split('/');
+                            let first = parts.next()?.parse::<u32>().ok()?;
+                            let second = parts.next()?.parse::<u32>().ok()?;
+                            Some((first, second))
+                        })
+                        .collect();
+
+                    let any_first_gt_12 = slash_parts.iter().any(|&(first, _)| first > 12);
+                    let any_second_gt_12 = slash_parts.iter().any(|&(_, second)| second > 12);
+
+                    if any_first_gt_12 {
+                        (Some("DD/MM/YYYY".to_string()), false)
+                    } else if any_second_gt_12 {
+                        (Some("MM/DD/YYYY".to_string()), false)
+                    } else {
+                        (Some("MM/DD/YYYY".to_string()), true)
+                    }
+                }
+            }
+            "datetime" => {
+                if values.iter().any(|v| DATETIME_PATTERN.is_match(v)) {
+                    (Some("RFC3339".to_string()), false)
+                } else {
+                    (None, false)
+                }
+            }
+            "time" => {
+                let has_seconds = values.iter()
+                    .filter(|v| TIME_PATTERN.is_match(v))
+                    .any(|v| v.matches(':').count() >= 2);
+                if values.iter().any(|v| TIME_PATTERN.is_match(v)) {
+                    let format = if has_seconds { "HH:MM:SS" } else { "HH:MM" };
+                    (Some(format.to_string()), false)
+                } else {
+                    (None, false)
+                }
+            }
+            _ => (None, false),
+        }
+    }
+
+    /// Parses a value into a comparable epoch-ish integer according to
+    /// `format`: days since 1970-01-01 for dates, UTC seconds since epoch for
+    /// datetimes, and seconds since midnight for times.
+    fn parse_temporal(value: &str, type_name: &str, format: &str) -> Option<i64> {
+        match type_name {
+            "date" => {
+                let (y, m, d) = Self::parse_date_parts(value, format)?;
+                Some(Self::days_from_civil(y, m, d))
+            }
+            "datetime" => {
+                let (date_part, time_part) = value.split_once(['T', ' '])?;
+                let (y, m, d) = Self::parse_date_parts(date_part, "YYYY-MM-DD")?;
+                let days = Self::days_from_civil(y, m, d);
+
+                let (time_part, offset_seconds) = if let Some(stripped) = time_part.strip_suffix('Z') {
+                    (stripped, 0)
+                } else if let Some(idx) = time_part.rfind(['+', '-']) {
+                    let (time, offset) = time_part.split_at(idx);
+                    (time, Self::parse_offset_seconds(offset).unwrap_or(0))
+                } else {
+                    (time_part, 0)
+                };
+                let time_part = time_part.split('.').next().unwrap_or(time_part);
+
+                let seconds_since_midnight = Self::parse_time_seconds(time_part)?;
+                Some(days * 86_400 + seconds_since_midnight - offset_seconds)
+            }
+            "time" => Self::parse_time_seconds(value),
+            _ => None,
+        }
+    }
+
+    fn parse_date_parts(value: &str, format: &str) -> Option<(i64, i64, i64)> {
+        match format {
+            "YYYY-MM-DD" => {
+                let parts: Vec<&str> = value.split('-').collect();
+                if parts.len() != 3 {
+                    return None;
+                }
+                Some((parts[0].parse().ok()?, parts[1].parse().ok()?, parts[2].parse().ok()?))
+            }
+            "DD/MM/YYYY" => {
+                let parts: Vec<&str> = value.split('/').collect();
+                if parts.len() != 3 {
+                    return None;
+                }
+                Some((parts[2].parse().ok()?, parts[1].parse().ok()?, parts[0].parse().ok()?))
+            }
+            "MM/DD/YYYY" => {
+                let parts: Vec<&str> = value.split('/').collect();
+                if parts.len() != 3 {
+                    return None;
+                }
+                Some((parts[2].parse().ok()?, parts[0].parse().ok()?, parts[1].parse().ok()?))
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_time_seconds(value: &str) -> Option<i64> {
+        let value = value.split('.').next().unwrap_or(value);
+        let parts: Vec<&str> = value.split(':').collect();
+        if parts.len() < 2 {
+            return None;
+        }
+        let hours: i64 = parts[0].parse().ok()?;
+        let minutes: i64 = parts[1].parse().ok()?;
+        let seconds: i64 = parts.get(2).and_then(|s| s.parse().ok()).unwrap_or(0);
+        Some(hours * 3600 + minutes * 60 + seconds)
+    }
+
+    fn parse_offset_seconds(offset: &str) -> Option<i64> {
+        let sign = if offset.starts_with('-') { -1 } else { 1 };
+        let offset = offset.trim_start_matches(['+', '-']).replace(':', "");
+        if offset.len() < 4 {
+            return None;
+        }
+        let hours: i64 = offset[0..2].parse().ok()?;
+        let minutes: i64 = offset[2..4].parse().ok()?;
+        Some(sign * (hours * 3600 + minutes * 60))
+    }
+
+    /// Days since 1970-01-01 for a proleptic Gregorian calendar date
+    /// (Howard Hinnant's `days_from_civil` algorithm).
+    fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+        let y = if m <= 2 { y - 1 } else { y };
+        let era = if y >= 0 { y } else { y - 399 } / 400;
+        let yoe = y - era * 400;
+        let mp = if m > 2 { m - 3 } else { m + 9 };
+        let doy = (153 * mp + 2) / 5 + d - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146097 + doe - 719468
+    }
+
+    fn compute_numeric_stats(&self, values: &[&str], type_name: &str) -> (Option<NumericStats>, usize) {
+        let sample_size = self.get_sample_size(values.len());
+
+        if type_name != "integer" && type_name != "float" {
+            return (None, sample_size);
+        }
+
+        let use_streaming_quantiles = self.config.streaming || self.config.sample_size.is_some();
+
+        let mut count = 0usize;
+        let mut sum = 0f64;
+        let mut mean = 0f64;
+        let mut m2 = 0f64;
+        let mut zero_count = 0usize;
+        let mut exact_values: Vec<f64> = Vec::new();
+        let mut p2 = P2QuantileEstimator::new();
+
+        for value in values.iter().take(sample_size) {
+            let value = value.trim().replace(',', ".");
+            if value.is_empty() {
+                continue;
+            }
+
+            let x = match value.parse::<f64>() {
+                Ok(x) => x,
+                Err(_) => continue,
+            };
+
+            count += 1;
+            sum += x;
+            if x == 0.0 {
+                zero_count += 1;
+            }
+
+            let delta = x - mean;
+            mean += delta / count as f64;
+            m2 += delta * (x - mean);
+
+            if use_streaming_quantiles {
+                p2.observe(x);
+            } else {
+                exact_values.push(x);
+            }
+        }
+
+        if count == 0 {
+            return (Some(NumericStats {
+                count,
+                sum,
+                mean: None,
+                variance: None,
+                sample_variance: None,
+                stddev: None,
+                sparsity: None,
+                median: None,
+                q1: None,
+                q3: None,
+                iqr: None,
+                lower_fence: None,
+                upper_fence: None,
+                skewness: None,
+            }), sample_size);
+        }
+
+        let sparsity = Some(zero_count as f64 / count as f64);
+
+        let (variance, sample_variance, stddev) = if count < 2 {
+            (None, None, None)
+        } else {
+            let variance = m2 / count as f64;
+            let sample_variance = m2 / (count as f64 - 1.0);
+            (Some(variance), Some(sample_variance), Some(variance.sqrt()))
+        };
+
+        let (_min, q1, median, q3, _max) = if use_streaming_quantiles {
+            p2.finish()
+        } else {
+            exact_values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            let n = exact_values.len();
+            let percentile = |p: f64| -> f64 {
+                let idx = p * (n as f64 - 1.0);
+                let lo = idx.floor() as usize;
+                let hi = idx.ceil() as usize;
+                if lo == hi {
+                    exact_values[lo]
+                } else {
+                    exact_values[lo] + (idx - lo as f64) * (exact_values[hi] - exact_values[lo])
+                }
+            };
+            (
+                Some(exact_values[0]),
+                Some(percentile(0.25)),
+                Some(percentile(0.5)),
+                Some(percentile(0.75)),
+                Some(exact_values[n - 1]),
+            )
+        };
+
+        let iqr = match (q1, q3) {
+            (Some(q1), Some(q3)) => Some(q3 - q1),
+            _ => None,
+        };
+        let lower_fence = match (q1, iqr) {
+            (Some(q1), Some(iqr)) => Some(q1 - 1.5 * iqr),
+            _ => None,
+        };
+        let upper_fence = match (q3, iqr) {
+            (Some(q3), Some(iqr)) => Some(q3 + 1.5 * iqr),
+            _ => None,
+        };
+        // Bowley (quantile) skewness: robust to outliers and well-defined from
+        // the same quartiles we already track in both exact and streaming mode.
+        let skewness = match (q1, median, q3, iqr) {
+            (Some(q1), Some(median), Some(q3), Some(iqr)) if iqr != 0.0 => {
+                Some((q3 + q1 - 2.0 * median) / iqr)
+            }
+            _ => None,
+        };
+
+        (Some(NumericStats {
+            count,
+            sum,
+            mean: Some(mean),
+            variance,
+            sample_variance,
+            stddev,
+            sparsity,
+            median,
+            q1,
+            q3,
+            iqr,
+            lower_fence,
+            upper_fence,
+            skewness,
+        }), sample_size)
+    }
+
+    fn compute_frequency(&self, values: &[&str]) -> (FrequencyAnalysis, usize) {
+        let sample_size = self.get_sample_size(values.len());
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+
+        for value in values.iter().take(sample_size) {
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+            *counts.entry(value).or_insert(0) += 1;
+        }
+
+        let cardinality = counts.len();
+
+        let mut by_count: Vec<(&str, usize)> = counts.into_iter().collect();
+        by_count.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+        let top_values: Vec<FrequencyEntry> = by_count.iter()
+            .take(self.config.top_n)
+            .map(|(value, count)| FrequencyEntry { value: value.to_string(), count: *count })
+            .collect();
+
+        let all_unique = cardinality > 0 && by_count.iter().all(|(_, count)| *count == 1);
+
+        let (modes, mode_count) = match by_count.first() {
+            Some(&(_, top_count)) => {
+                let modes: Vec<String> = by_count.iter()
+                    .take_while(|(_, count)| *count == top_count)
+                    .map(|(value, _)| value.to_string())
+                    .collect();
+                (modes, top_count)
+            }
+            None => (Vec::new(), 0),
+        };
+
+        let (antimodes, antimode_count) = if all_unique {
+            (vec!["*ALL".to_string()], 1)
+        } else {
+            match by_count.last() {
+                Some(&(_, bottom_count)) => {
+                    let antimodes: Vec<String> = by_count.iter()
+                        .rev()
+                        .take_while(|(_, count)| *count == bottom_count)
+                        .map(|(value, _)| value.to_string())
+                        .collect();
+                    (antimodes, bottom_count)
+                }
+                None => (Vec::new(), 0),
+            }
+        };
+
+        let frequency_table = if self.config.include_frequency_table {
+            Some(by_count.into_iter()
+                .map(|(value, count)| FrequencyEntry { value: value.to_string(), count })
+                .collect())
+        } else {
+            None
+        };
+
+        (FrequencyAnalysis {
+            cardinality,
+            cardinality_is_estimate: false,
+            modes,
+            mode_count,
+            antimodes,
+            antimode_count,
+            top_values,
+            frequency_table,
+        }, sample_size)
+    }
+
     fn find_length_stats(&self, values: &[&str]) -> (usize, usize, usize) {
         let sample_size = self.get_sample_size(values.len());
         let sampled_values: Vec<&str> = values.iter()
@@ -260,12 +1290,12 @@ impl CSVAnalyzer {
         }
 
         let min_length = sampled_values.iter()
-            .map(|s| s.len())
+            .map(|s| s.chars().count())
             .min()
             .unwrap_or(0);
 
         let max_length = sampled_values.iter()
-            .map(|s| s.len())
+            .map(|s| s.chars().count())
             .max()
             .unwrap_or(0);
 
@@ -328,6 +1358,8 @@ impl CSVAnalyzer {
                 let (type_name, type_details, type_analyzed) = self.detect_column_type(&values_refs);
                 let (min_value, max_value, minmax_analyzed) = self.find_min_max(&values_refs, &type_name);
                 let (min_length, max_length, length_analyzed) = self.find_length_stats(&values_refs);
+                let (numeric_stats, numeric_analyzed) = self.compute_numeric_stats(&values_refs, &type_name);
+                let (frequency, frequency_analyzed) = self.compute_frequency(&values_refs);
 
                 let sample_values: Vec<String> = values_refs.iter()
                     .filter(|v| !v.trim().is_empty())
@@ -337,13 +1369,20 @@ impl CSVAnalyzer {
 
                 let total_count = values.len();
                 let valid_count = total_count - null_count;
-                let analyzed_count = std::cmp::min(type_analyzed, std::cmp::min(minmax_analyzed, length_analyzed));
+                let analyzed_count = std::cmp::min(
+                    type_analyzed,
+                    std::cmp::min(
+                        minmax_analyzed,
+                        std::cmp::min(length_analyzed, std::cmp::min(numeric_analyzed, frequency_analyzed)),
+                    ),
+                );
 
                 analysis_columns.push(Column {
                     name: header.clone(),
                     type_name,
                     type_details,
                     unique_values: unique_values.len(),
+                    unique_values_is_estimate: false,
                     null_count,
                     min_value,
                     max_value,
@@ -353,6 +1392,8 @@ impl CSVAnalyzer {
                     valid_count,
                     total_count,
                     analyzed_count,
+                    numeric_stats,
+                    frequency,
                 });
             }
         }
@@ -378,4 +1419,438 @@ impl CSVAnalyzer {
             }
         }
     }
+
+    /// Generates fake-but-realistic CSV data matching a previously computed
+    /// `Analysis` profile, for use as test fixtures or for sharing a
+    /// dataset's shape without leaking real values.
+    #[wasm_bindgen]
+    pub fn generate(&self, analysis: JsValue, rows: usize) -> Result<String, JsValue> {
+        console_log!("Starting synthetic CSV generation...");
+
+        let analysis: Analysis = serde_wasm_bindgen::from_value(analysis)
+            .map_err(|e| JsValue::from_str(&format!("Error reading analysis: {}", e)))?;
+
+        let delimiter = analysis.detected_delimiter.to_string();
+
+        let mut out = String::new();
+        let header_line: Vec<String> = analysis.columns.iter().map(|c| c.name.clone()).collect();
+        out.push_str(&header_line.join(&delimiter));
+        out.push('\n');
+
+        for _ in 0..rows {
+            let fields: Vec<String> = analysis.columns.iter()
+                .map(|column| self.generate_field(column))
+                .collect();
+            out.push_str(&fields.join(&delimiter));
+            out.push('\n');
+        }
+
+        console_log!("Generation complete");
+        Ok(out)
+    }
+
+    fn generate_field(&self, column: &Column) -> String {
+        if column.total_count > 0 {
+            let null_ratio = column.null_count as f64 / column.total_count as f64;
+            if Math::random() < null_ratio {
+                return String::new();
+            }
+        }
+
+        match column.type_name.as_str() {
+            "integer" => Self::generate_integer(column),
+            "float" => Self::generate_float(column),
+            "boolean" => if Math::random() < 0.5 { "true" } else { "false" }.to_string(),
+            "date" => Self::generate_date(column.type_details.detected_format.as_deref()),
+            "datetime" => Self::generate_datetime(),
+            "time" => Self::generate_time(),
+            "email" => Self::generate_email(),
+            "url" => Self::generate_url(),
+            "ip" => Self::generate_ip(),
+            _ => self.generate_text(column),
+        }
+    }
+
+    fn numeric_bounds(column: &Column) -> (f64, f64) {
+        let min = column.min_value.as_ref().and_then(|v| v.parse::<f64>().ok()).unwrap_or(0.0);
+        let max = column.max_value.as_ref().and_then(|v| v.parse::<f64>().ok()).unwrap_or(min + 100.0);
+        if max < min { (max, min) } else { (min, max) }
+    }
+
+    fn generate_integer(column: &Column) -> String {
+        let (min, max) = Self::numeric_bounds(column);
+        let value = min + Math::random() * (max - min);
+        (value.round() as i64).to_string()
+    }
+
+    fn generate_float(column: &Column) -> String {
+        let (min, max) = Self::numeric_bounds(column);
+        let value = min + Math::random() * (max - min);
+        value.to_string()
+    }
+
+    fn random_digit() -> char {
+        std::char::from_digit((Math::random() * 10.0) as u32, 10).unwrap_or('0')
+    }
+
+    fn random_digits(n: usize) -> String {
+        (0..n).map(|_| Self::random_digit()).collect()
+    }
+
+    fn random_alpha(n: usize) -> String {
+        const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+        (0..n)
+            .map(|_| ALPHABET[(Math::random() * ALPHABET.len() as f64) as usize] as char)
+            .collect()
+    }
+
+    /// Emits a random date in `format` (the column's `detected_format`, e.g.
+    /// `"DD/MM/YYYY"` or `"MM/DD/YYYY"`), defaulting to ISO `YYYY-MM-DD` when
+    /// the format is unknown — so regenerated data round-trips the same
+    /// format the original profile was detected in.
+    fn generate_date(format: Option<&str>) -> String {
+        let year = 1970 + (Math::random() * 55.0) as u32;
+        let month = 1 + (Math::random() * 12.0) as u32;
+        let day = 1 + (Math::random() * 28.0) as u32;
+
+        match format {
+            Some("DD/MM/YYYY") => format!("{:02}/{:02}/{:04}", day, month, year),
+            Some("MM/DD/YYYY") => format!("{:02}/{:02}/{:04}", month, day, year),
+            _ => format!("{:04}-{:02}-{:02}", year, month, day),
+        }
+    }
+
+    fn generate_time() -> String {
+        let hour = (Math::random() * 24.0) as u32;
+        let minute = (Math::random() * 60.0) as u32;
+        let second = (Math::random() * 60.0) as u32;
+        format!("{:02}:{:02}:{:02}", hour, minute, second)
+    }
+
+    fn generate_datetime() -> String {
+        format!("{}T{}Z", Self::generate_date(Some("YYYY-MM-DD")), Self::generate_time())
+    }
+
+    fn generate_email() -> String {
+        const DOMAINS: [&str; 3] = ["example.com", "mail.test", "sample.org"];
+        let local = Self::random_alpha(8);
+        let domain = DOMAINS[(Math::random() * DOMAINS.len() as f64) as usize];
+        format!("{}@{}", local, domain)
+    }
+
+    fn generate_url() -> String {
+        format!("https://{}.example.com", Self::random_alpha(6))
+    }
+
+    fn generate_ip() -> String {
+        format!("{}.{}.{}.{}",
+            (Math::random() * 256.0) as u8,
+            (Math::random() * 256.0) as u8,
+            (Math::random() * 256.0) as u8,
+            (Math::random() * 256.0) as u8)
+    }
+
+    /// Builds a k-gram -> next-char frequency map from a column's retained
+    /// sample values; `generate_text` walks it to synthesize a new string.
+    /// `None` in the per-gram distribution marks the end of the string.
+    fn build_markov_chain(samples: &[String], k: usize) -> HashMap<String, Vec<Option<char>>> {
+        let mut chain: HashMap<String, Vec<Option<char>>> = HashMap::new();
+
+        for sample in samples {
+            let chars: Vec<char> = sample.chars().collect();
+            if chars.len() < k {
+                continue;
+            }
+            for i in 0..=(chars.len() - k) {
+                let gram: String = chars[i..i + k].iter().collect();
+                let next = chars.get(i + k).copied();
+                chain.entry(gram).or_insert_with(Vec::new).push(next);
+            }
+        }
+
+        chain
+    }
+
+    fn generate_text(&self, column: &Column) -> String {
+        if column.sample_values.is_empty() {
+            return Self::random_alpha(column.min_length.max(1));
+        }
+
+        let shortest = column.sample_values.iter().map(|s| s.chars().count()).min().unwrap_or(0);
+        let k = if shortest >= 3 { 3 } else { 2.min(shortest.max(1)) };
+
+        let chain = Self::build_markov_chain(&column.sample_values, k);
+        if chain.is_empty() {
+            let seed = &column.sample_values[(Math::random() * column.sample_values.len() as f64) as usize];
+            return seed.clone();
+        }
+
+        let seed_sample = &column.sample_values[(Math::random() * column.sample_values.len() as f64) as usize];
+        let seed_chars: Vec<char> = seed_sample.chars().collect();
+        if seed_chars.len() < k {
+            return seed_sample.clone();
+        }
+        let mut gram: String = seed_chars[0..k].iter().collect();
+        let mut result = gram.clone();
+
+        let max_length = column.max_length.max(column.min_length).max(k);
+
+        while result.chars().count() < max_length {
+            let options = match chain.get(&gram) {
+                Some(options) => options,
+                None => break,
+            };
+            let choice = &options[(Math::random() * options.len() as f64) as usize];
+            let next_char = match choice {
+                Some(c) => *c,
+                None => break,
+            };
+            result.push(next_char);
+            let result_chars: Vec<char> = result.chars().collect();
+            gram = result_chars[result_chars.len() - k..].iter().collect();
+        }
+
+        if result.chars().count() < column.min_length {
+            let padding = column.min_length - result.chars().count();
+            result.push_str(&Self::random_alpha(padding));
+        }
+
+        result
+    }
+
+    /// Starts a chunked analysis: call this once with the column headers and
+    /// delimiter, then stream the body through repeated `feed()` calls, then
+    /// call `finish()`. Unlike `analyze`, memory stays O(columns) regardless
+    /// of file size since each column retains only a bounded number of cell
+    /// values (a few samples and up to MAX_TRACKED_CARDINALITY distinct values).
+    #[wasm_bindgen]
+    pub fn begin(&mut self, headers: Vec<String>, delimiter: char) {
+        console_log!("Beginning chunked analysis of {} columns", headers.len());
+        self.stream = Some(StreamingState {
+            columns: headers.iter().map(|_| ColumnAccumulator::new()).collect(),
+            headers,
+            delimiter,
+            row_count: 0,
+            pending: String::new(),
+        });
+    }
+
+    /// Feeds the next slice of CSV body text (no header row) into the
+    /// in-progress chunked analysis started by `begin()`. Can be called
+    /// repeatedly as the caller streams a file or `ReadableStream` in
+    /// slices; a record split across two chunks is carried over correctly.
+    /// Note: unlike `analyze`, quoted fields containing literal newlines are
+    /// not supported, within a chunk or across a chunk boundary, since records
+    /// are recognized by newline rather than by a full streaming CSV parse.
+    #[wasm_bindgen]
+    pub fn feed(&mut self, chunk: &str) -> Result<(), JsValue> {
+        let state = self.stream.as_mut()
+            .ok_or_else(|| JsValue::from_str("feed() called before begin()"))?;
+
+        // Take `pending` out so the loop can hold an immutable slice into it
+        // while still passing `state` to `feed_line` mutably.
+        let mut buffer = std::mem::take(&mut state.pending);
+        buffer.push_str(chunk);
+
+        let mut consumed_to = 0;
+        let line_ends: Vec<usize> = buffer.match_indices('\n').map(|(offset, _)| offset).collect();
+        for offset in line_ends {
+            Self::feed_line(state, &buffer[consumed_to..offset]);
+            consumed_to = offset + 1;
+        }
+
+        state.pending = buffer.split_off(consumed_to);
+
+        Ok(())
+    }
+
+    fn feed_line(state: &mut StreamingState, line: &str) {
+        let line = line.trim_end_matches('\r');
+        if line.is_empty() {
+            return;
+        }
+
+        let fields = Self::split_csv_line(line, state.delimiter);
+
+        state.row_count += 1;
+        for (i, column) in state.columns.iter_mut().enumerate() {
+            column.observe(fields.get(i).map(|s| s.as_str()).unwrap_or(""));
+        }
+    }
+
+    /// Splits a single CSV record line on `delimiter`, honoring double-quoted
+    /// fields (with `""` as an escaped literal quote). A line-at-a-time
+    /// helper for the chunked API, cheaper than spinning up a full `csv::Reader`
+    /// per row; unlike `analyze()`'s reader it cannot handle a quoted field
+    /// that itself contains a newline.
+    fn split_csv_line(line: &str, delimiter: char) -> Vec<String> {
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut chars = line.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if in_quotes {
+                if c == '"' {
+                    if chars.peek() == Some(&'"') {
+                        field.push('"');
+                        chars.next();
+                    } else {
+                        in_quotes = false;
+                    }
+                } else {
+                    field.push(c);
+                }
+            } else if c == '"' && field.is_empty() {
+                in_quotes = true;
+            } else if c == delimiter {
+                fields.push(std::mem::take(&mut field));
+            } else {
+                field.push(c);
+            }
+        }
+        fields.push(field);
+
+        fields
+    }
+
+    /// Finalizes a chunked analysis started by `begin()`/`feed()` and
+    /// returns the same `Analysis` shape `analyze()` produces.
+    #[wasm_bindgen]
+    pub fn finish(&mut self) -> Result<JsValue, JsValue> {
+        let mut state = self.stream.take()
+            .ok_or_else(|| JsValue::from_str("finish() called before begin()"))?;
+
+        if !state.pending.is_empty() {
+            let remainder = std::mem::take(&mut state.pending);
+            Self::feed_line(&mut state, &remainder);
+        }
+
+        let row_count = state.row_count;
+        let headers = state.headers;
+        let delimiter = state.delimiter;
+        let columns: Vec<Column> = state.columns.into_iter()
+            .zip(headers.into_iter())
+            .map(|(accumulator, name)| accumulator.finish(name, &self.config))
+            .collect();
+
+        let analysis = Analysis {
+            row_count,
+            column_count: columns.len(),
+            columns,
+            detected_delimiter: delimiter,
+            sample_size: self.config.sample_size,
+        };
+
+        console_log!("Chunked analysis complete");
+
+        serde_wasm_bindgen::to_value(&analysis)
+            .map_err(|err| JsValue::from_str(&format!("Serialization error: {}", err)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exact_percentile(sorted: &[f64], p: f64) -> f64 {
+        let n = sorted.len();
+        let idx = p * (n as f64 - 1.0);
+        let lo = idx.floor() as usize;
+        let hi = idx.ceil() as usize;
+        if lo == hi {
+            sorted[lo]
+        } else {
+            sorted[lo] + (idx - lo as f64) * (sorted[hi] - sorted[lo])
+        }
+    }
+
+    #[test]
+    fn p2_quantiles_match_exact_reference_within_tolerance() {
+        let values: Vec<f64> = (1..=1000).map(|v| v as f64).collect();
+
+        let mut p2 = P2QuantileEstimator::new();
+        for &v in &values {
+            p2.observe(v);
+        }
+        let (min, q1, median, q3, max) = p2.finish();
+
+        let mut sorted = values.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert_eq!(min, Some(1.0));
+        assert_eq!(max, Some(1000.0));
+
+        let exact_q1 = exact_percentile(&sorted, 0.25);
+        let exact_median = exact_percentile(&sorted, 0.5);
+        let exact_q3 = exact_percentile(&sorted, 0.75);
+
+        // P^2 is an online approximation, not exact, so allow a small tolerance.
+        assert!((q1.unwrap() - exact_q1).abs() < 5.0, "q1: {:?} vs exact {}", q1, exact_q1);
+        assert!((median.unwrap() - exact_median).abs() < 5.0, "median: {:?} vs exact {}", median, exact_median);
+        assert!((q3.unwrap() - exact_q3).abs() < 5.0, "q3: {:?} vs exact {}", q3, exact_q3);
+    }
+
+    #[test]
+    fn p2_quantiles_with_fewer_than_five_values_are_exact() {
+        let mut p2 = P2QuantileEstimator::new();
+        for v in [10.0, 2.0, 8.0] {
+            p2.observe(v);
+        }
+        let (min, q1, median, q3, max) = p2.finish();
+
+        assert_eq!(min, Some(2.0));
+        assert_eq!(max, Some(10.0));
+        assert_eq!(median, Some(8.0));
+        assert_eq!(q1, Some(5.0));
+        assert_eq!(q3, Some(9.0));
+    }
+
+    #[test]
+    fn days_from_civil_matches_known_reference_dates() {
+        assert_eq!(CSVAnalyzer::days_from_civil(1970, 1, 1), 0);
+        assert_eq!(CSVAnalyzer::days_from_civil(1969, 12, 31), -1);
+        assert_eq!(CSVAnalyzer::days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(CSVAnalyzer::days_from_civil(2020, 1, 15), 18276);
+    }
+
+    #[test]
+    fn parse_temporal_orders_iso_dates_chronologically() {
+        let earlier = CSVAnalyzer::parse_temporal("2020-01-15", "date", "YYYY-MM-DD").unwrap();
+        let later = CSVAnalyzer::parse_temporal("2020-02-01", "date", "YYYY-MM-DD").unwrap();
+        assert!(earlier < later);
+    }
+
+    #[test]
+    fn parse_temporal_respects_slash_date_field_order() {
+        // "03/04/2020" is 3 April under DD/MM/YYYY but 4 March under MM/DD/YYYY.
+        let as_dd_mm = CSVAnalyzer::parse_temporal("03/04/2020", "date", "DD/MM/YYYY").unwrap();
+        let as_mm_dd = CSVAnalyzer::parse_temporal("03/04/2020", "date", "MM/DD/YYYY").unwrap();
+        assert_ne!(as_dd_mm, as_mm_dd);
+        assert_eq!(as_dd_mm, CSVAnalyzer::days_from_civil(2020, 4, 3));
+        assert_eq!(as_mm_dd, CSVAnalyzer::days_from_civil(2020, 3, 4));
+    }
+
+    #[test]
+    fn parse_temporal_applies_datetime_timezone_offset() {
+        // Same instant expressed in UTC and in a +02:00 offset should parse equal.
+        let utc = CSVAnalyzer::parse_temporal("2020-06-01T10:00:00Z", "datetime", "RFC3339").unwrap();
+        let offset = CSVAnalyzer::parse_temporal("2020-06-01T12:00:00+02:00", "datetime", "RFC3339").unwrap();
+        assert_eq!(utc, offset);
+    }
+
+    #[test]
+    fn infer_temporal_format_flags_ambiguous_only_when_neither_field_exceeds_12() {
+        let (format, ambiguous) = CSVAnalyzer::infer_temporal_format(&["01/02/2020", "03/04/2020"], "date");
+        assert_eq!(format, Some("MM/DD/YYYY".to_string()));
+        assert!(ambiguous);
+
+        let (format, ambiguous) = CSVAnalyzer::infer_temporal_format(&["01/15/2020", "02/28/2020"], "date");
+        assert_eq!(format, Some("MM/DD/YYYY".to_string()));
+        assert!(!ambiguous);
+
+        let (format, ambiguous) = CSVAnalyzer::infer_temporal_format(&["15/01/2020", "28/02/2020"], "date");
+        assert_eq!(format, Some("DD/MM/YYYY".to_string()));
+        assert!(!ambiguous);
+    }
 }
\ No newline at end of file